@@ -0,0 +1,111 @@
+//! ABI-style calldata encoding for on-chain Groth16 verifiers (the layout
+//! used by the Solidity verifiers that snarkjs/circom generate), so we can
+//! estimate on-chain verification cost without deploying a contract.
+
+use bellman_ce::groth16::{Proof, VerifyingKey};
+use bellman_ce::pairing::bn256;
+use bellman_ce::pairing::ff::{PrimeField, PrimeFieldRepr};
+use bellman_ce::pairing::{CurveAffine, Engine};
+
+/// Appends `value` as a single 32-byte big-endian word, the layout Solidity
+/// uses for each `uint256` calldata argument. Only fields with a
+/// representation of 32 bytes or fewer fit a single EVM word -- BN254's Fq
+/// and Fr both do, which is why `Fq2Coords` below is only implemented for
+/// `bn256::Fq2` and not BLS12-381 (whose 48-byte Fq would not fit).
+fn push_fq<F: PrimeField>(out: &mut Vec<u8>, value: &F) {
+    let mut bytes = vec![];
+    value.into_repr().write_be(&mut bytes).unwrap();
+    assert!(
+        bytes.len() <= 32,
+        "field element is {} bytes, which does not fit a 32-byte EVM word",
+        bytes.len()
+    );
+    // `write_be` emits the field's native byte width; left-pad to one EVM word.
+    let mut word = [0u8; 32];
+    word[32 - bytes.len()..].copy_from_slice(&bytes);
+    out.extend_from_slice(&word);
+}
+
+fn push_g1<E: Engine>(out: &mut Vec<u8>, p: &E::G1Affine) {
+    let (x, y) = p.into_xy_unchecked();
+    push_fq(out, &x);
+    push_fq(out, &y);
+}
+
+/// G2 points are over the quadratic extension Fq2; each coordinate is
+/// encoded as (c1, c0), the order on-chain Groth16 verifiers expect
+/// (matching the pairing precompile's G2 word layout).
+fn push_g2<E: Engine>(out: &mut Vec<u8>, p: &E::G2Affine)
+where
+    E::Fqe: Fq2Coords<Fq = E::Fq>,
+{
+    let (x, y) = p.into_xy_unchecked();
+    push_fq(out, &x.c1());
+    push_fq(out, &x.c0());
+    push_fq(out, &y.c1());
+    push_fq(out, &y.c0());
+}
+
+/// Minimal accessor for quadratic-extension coordinates, implemented below
+/// for whichever `Fqe` type the in-scope curve uses. This EVM calldata
+/// encoding only supports curves whose base field fits a 32-byte word
+/// (BN254), so only `bn256::Fq2` gets an impl -- BLS12-381's 48-byte Fq
+/// would need a different (64-byte-word) calldata layout entirely.
+pub trait Fq2Coords {
+    type Fq: PrimeField;
+    fn c0(&self) -> Self::Fq;
+    fn c1(&self) -> Self::Fq;
+}
+
+impl Fq2Coords for bn256::Fq2 {
+    type Fq = bn256::Fq;
+    fn c0(&self) -> bn256::Fq {
+        self.c0
+    }
+    fn c1(&self) -> bn256::Fq {
+        self.c1
+    }
+}
+
+/// Encodes a Groth16 proof plus its public inputs as EVM calldata: `a` (G1),
+/// `b` (G2), `c` (G1), followed by one 32-byte word per public input.
+pub fn to_evm_calldata<E: Engine>(proof: &Proof<E>, public_inputs: &[E::Fr]) -> Vec<u8>
+where
+    E::Fqe: Fq2Coords<Fq = E::Fq>,
+{
+    let mut out = Vec::with_capacity((2 + 4 + 2 + public_inputs.len()) * 32);
+    push_g1::<E>(&mut out, &proof.a);
+    push_g2::<E>(&mut out, &proof.b);
+    push_g1::<E>(&mut out, &proof.c);
+    for input in public_inputs {
+        push_fq(&mut out, input);
+    }
+    out
+}
+
+/// Encodes the parts of a verifying key an on-chain verifier needs:
+/// alpha (G1), beta/gamma/delta (G2), and the IC basis (one G1 per
+/// public input, plus the constant term).
+pub fn vk_to_evm_calldata<E: Engine>(vk: &VerifyingKey<E>) -> Vec<u8>
+where
+    E::Fqe: Fq2Coords<Fq = E::Fq>,
+{
+    let mut out = Vec::with_capacity((2 + 4 + 4 + 4 + vk.ic.len() * 2) * 32);
+    push_g1::<E>(&mut out, &vk.alpha_g1);
+    push_g2::<E>(&mut out, &vk.beta_g2);
+    push_g2::<E>(&mut out, &vk.gamma_g2);
+    push_g2::<E>(&mut out, &vk.delta_g2);
+    for ic in &vk.ic {
+        push_g1::<E>(&mut out, ic);
+    }
+    out
+}
+
+/// A verifier contract for a Groth16 proof with `num_public_inputs` always
+/// performs the same fixed number of EC operations, regardless of circuit
+/// size: 3 pairings (the batched e(A,B) check against alpha/beta, gamma,
+/// delta) and one G1 scalar multiplication per public input (to fold the
+/// inputs into the IC accumulator).
+pub fn onchain_ec_ops(num_public_inputs: usize) -> (usize, usize) {
+    (3, num_public_inputs)
+}
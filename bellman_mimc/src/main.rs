@@ -1,18 +1,40 @@
 use rand::thread_rng;
 use std::time::{Instant};
-use bellman_ce::pairing::Engine;
+use bellman_ce::pairing::{CurveAffine, CurveProjective, Engine};
 use bellman_ce::pairing::bn256::{Bn256}; // use BN256 curve
-use bellman_ce::pairing::ff::{Field, ScalarEngine};
+use bellman_ce::pairing::bls12_381::{Bls12}; // use BLS12-381 curve
+use bellman_ce::pairing::ff::{Field, PrimeField, ScalarEngine};
 use bellman_ce::{Circuit, ConstraintSystem, SynthesisError};
 use bellman_ce::groth16::{
-    create_random_proof, generate_random_parameters, prepare_verifying_key, verify_proof, Proof,
+    create_random_proof, generate_random_parameters, prepare_verifying_key, verify_proof,
+    Parameters, PreparedVerifyingKey, Proof,
 };
 
-use rand::{self, Rand};
+use rand::{self, Rand, Rng};
+
+mod evm;
+mod results;
+use evm::{onchain_ec_ops, to_evm_calldata, vk_to_evm_calldata};
+use results::{Measurement, RunRecord};
+
+fn duration_secs(d: std::time::Duration) -> f64 {
+    d.subsec_nanos() as f64 / 1_000_000_000f64 + d.as_secs() as f64
+}
 
 const MIMC_ROUNDS: usize = 91;
 const MIMC_STEP: usize = 200;
 
+// Round count for the two-rail x^3 Feistel permutation (LongsightF322p3)
+// below. `MiMCDemo` and `MiMCFeistelDemo` take ROUNDS as a const generic
+// parameter so the two permutations can be benchmarked side by side
+// without duplicating the round-count bookkeeping; the exponent (7 vs 3)
+// is fixed per struct by its constraint chain, not a generic parameter.
+const MIMC_FEISTEL_ROUNDS: usize = 322;
+
+// Batch sizes swept by `bench_batch_verification` to find the crossover
+// where verifying proofs as a batch beats verifying them one at a time.
+const BATCH_SIZES: [usize; 4] = [1, 8, 64, 256];
+
 /// This is an implementation of the MiMC block cipher,
 /// for the BN256 curve. Uses x^7 powering sequence
 /// See http://eprint.iacr.org/2016/492 for more
@@ -29,9 +51,9 @@ const MIMC_STEP: usize = 200;
 /// }
 /// ```
 fn mimc<E: Engine>(mut x: E::Fr, k: E::Fr, constants: &[E::Fr]) -> E::Fr {
-    assert_eq!(constants.len(), MIMC_ROUNDS);
+    let rounds = constants.len();
 
-    for i in 0..MIMC_ROUNDS {
+    for i in 0..rounds {
         // tmp1 = x + k + c[i]
         let mut tmp1 = x;
         tmp1.add_assign(&constants[i]);
@@ -54,16 +76,20 @@ fn mimc<E: Engine>(mut x: E::Fr, k: E::Fr, constants: &[E::Fr]) -> E::Fr {
     x
 }
 
-struct MiMCDemo<'a, E: Engine> {
+// The constraint chain in `synthesize` below computes x^7 via two
+// squarings and two multiplications, so this circuit is fixed to the x^7
+// permutation; ROUNDS is the only knob left generic. See `MiMCFeistelDemo`
+// for the x^3 two-rail permutation.
+struct MiMCDemo<'a, E: Engine, const ROUNDS: usize = MIMC_ROUNDS> {
     repetitions: usize,
     x: Option<E::Fr>,
     k: Option<E::Fr>,
     constants: &'a [E::Fr],
 }
 
-impl<'a, E: Engine> Circuit<E> for MiMCDemo<'a, E> {
+impl<'a, E: Engine, const ROUNDS: usize> Circuit<E> for MiMCDemo<'a, E, ROUNDS> {
     fn synthesize<CS: ConstraintSystem<E>>(self, cs: &mut CS) -> Result<(), SynthesisError> {
-        assert_eq!(self.constants.len(), MIMC_ROUNDS);
+        assert_eq!(self.constants.len(), ROUNDS);
 
         for _ in 0..(self.repetitions) {
 
@@ -80,7 +106,7 @@ impl<'a, E: Engine> Circuit<E> for MiMCDemo<'a, E> {
             || k_value.ok_or(SynthesisError::AssignmentMissing),
         )?;
 
-        for i in 0..MIMC_ROUNDS {
+        for i in 0..ROUNDS {
             // x := (x + k + Ci)^7
             let cs = &mut cs.namespace(|| format!("round {}", i));
 
@@ -147,7 +173,7 @@ impl<'a, E: Engine> Circuit<E> for MiMCDemo<'a, E> {
                 e.mul_assign(&rhs_value.unwrap());
                 e
             });
-            let new_x = if i == (MIMC_ROUNDS - 1) {
+            let new_x = if i == (ROUNDS - 1) {
                 cs.alloc_input(
                     || "image",
                     || new_x_value.ok_or(SynthesisError::AssignmentMissing),
@@ -174,6 +200,348 @@ impl<'a, E: Engine> Circuit<E> for MiMCDemo<'a, E> {
     }
 }
 
+/// This is the two-rail MiMC Feistel construction (LongsightF322p3), the
+/// x^3 variant used across the BLS/Zcash ecosystem. The state is split into
+/// (xL, xR) and each round only cubes one half:
+///
+/// ```
+/// function MiMC_Feistel(xL ⦂ Fp, xR ⦂ Fp) {
+///     for i from 0 up to 322 {
+///         xL, xR := xR + (xL + Ci)^3, xL
+///     }
+///     return xL
+/// }
+/// ```
+fn mimc_feistel<E: Engine>(mut xl: E::Fr, mut xr: E::Fr, constants: &[E::Fr]) -> E::Fr {
+    let rounds = constants.len();
+
+    for i in 0..rounds {
+        // tmp = (xL + Ci)^2
+        let mut tmp = xl;
+        tmp.add_assign(&constants[i]);
+        tmp.square();
+        // tmp = (xL + Ci)^3
+        let mut lhs = xl;
+        lhs.add_assign(&constants[i]);
+        tmp.mul_assign(&lhs);
+        // new_xR = xR + (xL + Ci)^3
+        tmp.add_assign(&xr);
+
+        xr = xl;
+        xl = tmp;
+    }
+
+    xl
+}
+
+// The constraint pair in `synthesize` below computes (xL + Ci)^3 via one
+// squaring and one multiplication, so this circuit is fixed to the x^3
+// permutation; ROUNDS is the only knob left generic. See `MiMCDemo` for
+// the x^7 single-rail permutation.
+struct MiMCFeistelDemo<'a, E: Engine, const ROUNDS: usize = MIMC_FEISTEL_ROUNDS> {
+    repetitions: usize,
+    xl: Option<E::Fr>,
+    xr: Option<E::Fr>,
+    constants: &'a [E::Fr],
+}
+
+impl<'a, E: Engine, const ROUNDS: usize> Circuit<E> for MiMCFeistelDemo<'a, E, ROUNDS> {
+    fn synthesize<CS: ConstraintSystem<E>>(self, cs: &mut CS) -> Result<(), SynthesisError> {
+        assert_eq!(self.constants.len(), ROUNDS);
+
+        for _ in 0..(self.repetitions) {
+
+        let mut xl_value = self.xl;
+        let mut xl = cs.alloc(
+            || "preimage xL",
+            || xl_value.ok_or(SynthesisError::AssignmentMissing),
+        )?;
+
+        let mut xr_value = self.xr;
+        let mut xr = cs.alloc(
+            || "preimage xR",
+            || xr_value.ok_or(SynthesisError::AssignmentMissing),
+        )?;
+
+        for i in 0..ROUNDS {
+            let cs = &mut cs.namespace(|| format!("round {}", i));
+
+            // t = (xL + Ci)^2
+            let t_value = xl_value.map(|mut e| {
+                e.add_assign(&self.constants[i]);
+                e.square();
+                e
+            });
+            let t = cs.alloc(
+                || "t",
+                || t_value.ok_or(SynthesisError::AssignmentMissing),
+            )?;
+            cs.enforce(
+                || "t = (xL + Ci)^2",
+                |lc| lc + xl + (self.constants[i], CS::one()),
+                |lc| lc + xl + (self.constants[i], CS::one()),
+                |lc| lc + t,
+            );
+
+            // new_xL = t * (xL + Ci) + xR = (xL + Ci)^3 + xR
+            let new_xl_value = t_value.map(|mut e| {
+                let mut rhs = xl_value.unwrap();
+                rhs.add_assign(&self.constants[i]);
+                e.mul_assign(&rhs);
+                e.add_assign(&xr_value.unwrap());
+                e
+            });
+            let new_xl = if i == (ROUNDS - 1) {
+                cs.alloc_input(
+                    || "image",
+                    || new_xl_value.ok_or(SynthesisError::AssignmentMissing),
+                )?
+            } else {
+                cs.alloc(
+                    || "new_xL",
+                    || new_xl_value.ok_or(SynthesisError::AssignmentMissing),
+                )?
+            };
+            cs.enforce(
+                || "new_xL = (xL + Ci).t + xR",
+                |lc| lc + t,
+                |lc| lc + xl + (self.constants[i], CS::one()),
+                |lc| lc + new_xl - xr,
+            );
+
+            xr = xl;
+            xr_value = xl_value;
+            xl = new_xl;
+            xl_value = new_xl_value;
+        }
+    }
+        Ok(())
+    }
+}
+
+// bellman_ce does not expose `create_random_proof_batch` / `verify_proofs_batch`
+// (unlike bellperson), so batching is implemented here directly.
+//
+// Individually, verifying N proofs costs N independent pairing checks:
+//     e(A_i, B_i) =?= e(alpha, beta) . e(sum_inputs_i, gamma) . e(C_i, delta)
+// each of which needs its own miller loop *and* final exponentiation.
+//
+// Batching randomizes each check by a fresh scalar r_i and folds it into a
+// single equation:
+//     prod_i e(A_i, B_i)^{r_i} =?= (e(alpha, beta) . e(sum_inputs_i, gamma) . e(C_i, delta))^{r_i}
+// Since e(A, B)^r = e(r.A, B), the left-hand side collapses into one miller
+// loop over the (scaled A_i, B_i) pairs, and the right-hand side collapses
+// into a single extra miller loop term per accumulated input/C point plus
+// one GT exponentiation -- turning 3N pairings (and N final
+// exponentiations) into a single final exponentiation over O(N) miller
+// loop terms. A malicious prover satisfying the batch equation but not
+// every individual one would need to guess the r_i in advance, which holds
+// with overwhelming probability for random r_i.
+fn verify_proofs_batch<E: Engine, R: Rng>(
+    pvk: &PreparedVerifyingKey<E>,
+    rng: &mut R,
+    proofs: &[Proof<E>],
+    public_inputs: &[Vec<E::Fr>],
+) -> Result<bool, SynthesisError> {
+    assert_eq!(proofs.len(), public_inputs.len());
+
+    let mut miller_inputs = Vec::with_capacity(proofs.len() + 2);
+    let mut acc_for_gamma = <E::G1Affine as CurveAffine>::Projective::zero();
+    let mut acc_for_delta = <E::G1Affine as CurveAffine>::Projective::zero();
+    let mut sum_r = E::Fr::zero();
+
+    let mut prepared_b = Vec::with_capacity(proofs.len());
+    for (proof, inputs) in proofs.iter().zip(public_inputs.iter()) {
+        if (inputs.len() + 1) != pvk.ic.len() {
+            return Err(SynthesisError::MalformedVerifyingKey);
+        }
+
+        let r = E::Fr::rand(rng);
+        sum_r.add_assign(&r);
+
+        let mut scaled_a = proof.a.into_projective();
+        scaled_a.mul_assign(r.into_repr());
+        prepared_b.push(proof.b.prepare());
+        miller_inputs.push(scaled_a.into_affine());
+
+        let mut acc = pvk.ic[0].into_projective();
+        for (input, base) in inputs.iter().zip(pvk.ic.iter().skip(1)) {
+            acc.add_assign(&base.mul(input.into_repr()));
+        }
+        acc.mul_assign(r.into_repr());
+        acc_for_gamma.add_assign(&acc);
+
+        let mut scaled_c = proof.c.into_projective();
+        scaled_c.mul_assign(r.into_repr());
+        acc_for_delta.add_assign(&scaled_c);
+    }
+
+    let acc_for_gamma = acc_for_gamma.into_affine().prepare();
+    let acc_for_delta = acc_for_delta.into_affine().prepare();
+    let prepared_a: Vec<_> = miller_inputs.iter().map(|a| a.prepare()).collect();
+
+    let mut pairs: Vec<_> = prepared_a.iter().zip(prepared_b.iter()).collect();
+    pairs.push((&acc_for_gamma, &pvk.neg_gamma_g2));
+    pairs.push((&acc_for_delta, &pvk.neg_delta_g2));
+
+    let lhs = E::final_exponentiation(&E::miller_loop(pairs.iter().map(|(a, b)| (*a, *b)))).unwrap();
+    let rhs = pvk.alpha_g1_beta_g2.pow(sum_r.into_repr());
+
+    Ok(lhs == rhs)
+}
+
+fn bench_batch_verification<E: Engine>(params: &Parameters<E>, constants: &[E::Fr])
+where
+    E::Fr: Rand,
+{
+    let rng = &mut thread_rng();
+    let pvk = prepare_verifying_key(&params.vk);
+
+    let x = E::Fr::rand(rng);
+    let k = E::Fr::rand(rng);
+    let image = mimc::<E>(x, k, constants);
+
+    for &batch_size in BATCH_SIZES.iter() {
+        let proofs: Vec<Proof<E>> = (0..batch_size)
+            .map(|_| {
+                let c = MiMCDemo::<E> {
+                    repetitions: 1,
+                    x: Some(x),
+                    k: Some(k),
+                    constants,
+                };
+                create_random_proof(c, params, rng).unwrap()
+            })
+            .collect();
+
+        let start = Instant::now();
+        for _ in 0..batch_size {
+            let c = MiMCDemo::<E> {
+                repetitions: 1,
+                x: Some(x),
+                k: Some(k),
+                constants,
+            };
+            create_random_proof(c, params, rng).unwrap();
+        }
+        let batch_proving = start.elapsed();
+        let per_proof_proving =
+            batch_proving.as_secs() as f64 + (batch_proving.subsec_nanos() as f64 / 1e9);
+        let per_proof_proving = per_proof_proving / (batch_size as f64);
+
+        let inputs: Vec<Vec<E::Fr>> = (0..batch_size).map(|_| vec![image]).collect();
+
+        let start = Instant::now();
+        for proof in proofs.iter() {
+            assert!(verify_proof(&pvk, proof, &[image]).unwrap());
+        }
+        let individual_verifying = start.elapsed();
+
+        let start = Instant::now();
+        assert!(verify_proofs_batch(&pvk, rng, &proofs, &inputs).unwrap());
+        let batch_verifying = start.elapsed();
+
+        println!(
+            "batch size {:4}: per-proof proving {:?}, individual verify {:?}, batch verify {:?}",
+            batch_size, per_proof_proving, individual_verifying, batch_verifying
+        );
+    }
+}
+
+struct CurveBenchResult {
+    curve_name: &'static str,
+    constraints: usize,
+    param_gen: std::time::Duration,
+    proving: std::time::Duration,
+    verifying: std::time::Duration,
+    proof_size_bytes: usize,
+}
+
+// Runs the single-rail MiMC circuit for one repetition on the given curve,
+// so the same construction can be compared across curves side by side.
+fn bench_mimc_curve<E: Engine>(curve_name: &'static str) -> CurveBenchResult
+where
+    E::Fr: Rand,
+{
+    let rng = &mut thread_rng();
+
+    let constants = (0..MIMC_ROUNDS)
+        .map(|_| E::Fr::rand(rng))
+        .collect::<Vec<_>>();
+
+    let start = Instant::now();
+    let params = {
+        let c = MiMCDemo::<E> {
+            repetitions: 1,
+            x: None,
+            k: None,
+            constants: &constants,
+        };
+        generate_random_parameters(c, rng).unwrap()
+    };
+    let param_gen = start.elapsed();
+
+    let pvk = prepare_verifying_key(&params.vk);
+
+    let x = E::Fr::rand(rng);
+    let k = E::Fr::rand(rng);
+    let image = mimc::<E>(x, k, &constants);
+
+    let c = MiMCDemo::<E> {
+        repetitions: 1,
+        x: Some(x),
+        k: Some(k),
+        constants: &constants,
+    };
+
+    let start = Instant::now();
+    let proof = create_random_proof(c, &params, rng).unwrap();
+    let proving = start.elapsed();
+
+    let mut proof_vec = vec![];
+    proof.write(&mut proof_vec).unwrap();
+
+    let start = Instant::now();
+    assert!(verify_proof(&pvk, &proof, &[image]).unwrap());
+    let verifying = start.elapsed();
+
+    CurveBenchResult {
+        curve_name,
+        constraints: params.a.len(),
+        param_gen,
+        proving,
+        verifying,
+        proof_size_bytes: proof_vec.len(),
+    }
+}
+
+fn print_curve_comparison(results: &[CurveBenchResult]) {
+    println!(
+        "{:>10} | {:>12} | {:>15} | {:>15} | {:>15}",
+        "curve", "constraints", "param-gen", "proving", "verifying"
+    );
+    for r in results {
+        println!(
+            "{:>10} | {:>12} | {:>15?} | {:>15?} | {:>15?}",
+            r.curve_name, r.constraints, r.param_gen, r.proving, r.verifying
+        );
+    }
+}
+
+impl CurveBenchResult {
+    fn to_measurement(&self, circuit: &str) -> Measurement {
+        Measurement {
+            circuit: circuit.to_string(),
+            input_size: 1,
+            constraints: self.constraints,
+            param_gen_secs: duration_secs(self.param_gen),
+            proving_secs: duration_secs(self.proving),
+            verifying_secs: duration_secs(self.verifying),
+            proof_size_bytes: self.proof_size_bytes,
+        }
+    }
+}
+
 // #[test]
 fn main() {
     // This may not be cryptographically safe, use
@@ -192,12 +560,15 @@ fn main() {
     // benchmark deserialization.
     let mut proof_vec = vec![];
 
+    let mut run = RunRecord::new("BN256");
+
     for sample_idx in 0..SAMPLES {
 
         println!("Creating parameters...");
         let num_repetitions = ((sample_idx as usize) + 1) * MIMC_STEP;
 
         // Create parameters for our circuit
+        let param_gen_start = Instant::now();
         let params = {
             let c = MiMCDemo::<Bn256> {
                 repetitions: num_repetitions,
@@ -205,10 +576,11 @@ fn main() {
                 k: None,
                 constants: &constants,
             };
-    
+
             generate_random_parameters(c, rng).unwrap()
         };
-    
+        let param_gen = param_gen_start.elapsed();
+
         // Prepare the verification key (for proof verification)
         let pvk = prepare_verifying_key(&params.vk);
     
@@ -258,7 +630,161 @@ fn main() {
 
         println!("applying MiMC cipher: {:?} times", num_repetitions);
         println!("proving time: {:?} seconds", proving_avg);
-        println!("verifying time: {:?} seconds", verifying_avg);    
+        println!("verifying time: {:?} seconds", verifying_avg);
+
+        run.push(Measurement {
+            circuit: "MiMC-x7".to_string(),
+            input_size: num_repetitions,
+            constraints: params.a.len(),
+            param_gen_secs: duration_secs(param_gen),
+            proving_secs: proving_avg,
+            verifying_secs: verifying_avg,
+            proof_size_bytes: proof_vec.len(),
+        });
+    }
+
+    // Batch proof generation/verification: single-repetition MiMC circuit,
+    // swept across BATCH_SIZES to find where batch verification wins over
+    // verifying each proof one at a time.
+    println!("Benchmarking batch verification...");
+    let params = {
+        let c = MiMCDemo::<Bn256> {
+            repetitions: 1,
+            x: None,
+            k: None,
+            constants: &constants,
+        };
+
+        generate_random_parameters(c, rng).unwrap()
+    };
+    bench_batch_verification::<Bn256>(&params, &constants);
+
+    // Compare the x^7 single-rail MiMC permutation against the x^3
+    // two-rail Feistel permutation (LongsightF322p3): same repetitions,
+    // constraints/round and total prover time side by side.
+    println!("Benchmarking single-rail x^7 MiMC vs two-rail x^3 MiMC Feistel...");
+    let feistel_constants = (0..MIMC_FEISTEL_ROUNDS)
+        .map(|_| <Bn256 as ScalarEngine>::Fr::rand(rng))
+        .collect::<Vec<_>>();
+
+    let single_rail_params = {
+        let c = MiMCDemo::<Bn256> {
+            repetitions: 1,
+            x: None,
+            k: None,
+            constants: &constants,
+        };
+        generate_random_parameters(c, rng).unwrap()
+    };
+    println!(
+        "single-rail x^7: {} rounds, {} constraints, {:.2} constraints/round",
+        MIMC_ROUNDS,
+        single_rail_params.a.len(),
+        (single_rail_params.a.len() as f64) / (MIMC_ROUNDS as f64)
+    );
+    let single_rail_x = <Bn256 as ScalarEngine>::Fr::rand(rng);
+    let single_rail_k = <Bn256 as ScalarEngine>::Fr::rand(rng);
+    let c = MiMCDemo::<Bn256> {
+        repetitions: 1,
+        x: Some(single_rail_x),
+        k: Some(single_rail_k),
+        constants: &constants,
+    };
+    let start = Instant::now();
+    create_random_proof(c, &single_rail_params, rng).unwrap();
+    println!("single-rail x^7 proving time: {:?}", start.elapsed());
+
+    let feistel_param_gen_start = Instant::now();
+    let feistel_params = {
+        let c = MiMCFeistelDemo::<Bn256> {
+            repetitions: 1,
+            xl: None,
+            xr: None,
+            constants: &feistel_constants,
+        };
+        generate_random_parameters(c, rng).unwrap()
+    };
+    let feistel_param_gen = feistel_param_gen_start.elapsed();
+    println!(
+        "two-rail x^3 Feistel: {} rounds, {} constraints, {:.2} constraints/round",
+        MIMC_FEISTEL_ROUNDS,
+        feistel_params.a.len(),
+        (feistel_params.a.len() as f64) / (MIMC_FEISTEL_ROUNDS as f64)
+    );
+
+    let xl = <Bn256 as ScalarEngine>::Fr::rand(rng);
+    let xr = <Bn256 as ScalarEngine>::Fr::rand(rng);
+    let feistel_image = mimc_feistel::<Bn256>(xl, xr, &feistel_constants);
+    let c = MiMCFeistelDemo::<Bn256> {
+        repetitions: 1,
+        xl: Some(xl),
+        xr: Some(xr),
+        constants: &feistel_constants,
+    };
+    let start = Instant::now();
+    let feistel_proof = create_random_proof(c, &feistel_params, rng).unwrap();
+    let feistel_proving = start.elapsed();
+    println!("Feistel proving time: {:?}", feistel_proving);
+
+    let mut feistel_proof_vec = vec![];
+    feistel_proof.write(&mut feistel_proof_vec).unwrap();
+
+    let feistel_pvk = prepare_verifying_key(&feistel_params.vk);
+    let start = Instant::now();
+    assert!(verify_proof(&feistel_pvk, &feistel_proof, &[feistel_image]).unwrap());
+    let feistel_verifying = start.elapsed();
+
+    run.push(Measurement {
+        circuit: "MiMC-Feistel-x3".to_string(),
+        input_size: 1,
+        constraints: feistel_params.a.len(),
+        param_gen_secs: duration_secs(feistel_param_gen),
+        proving_secs: duration_secs(feistel_proving),
+        verifying_secs: duration_secs(feistel_verifying),
+        proof_size_bytes: feistel_proof_vec.len(),
+    });
+
+    // Same circuit, different curve: how much does the curve choice cost?
+    println!("Benchmarking MiMC across curves...");
+    let curve_results = vec![
+        bench_mimc_curve::<Bn256>("BN256"),
+        bench_mimc_curve::<Bls12>("BLS12-381"),
+    ];
+    print_curve_comparison(&curve_results);
+    for r in &curve_results {
+        run.push(r.to_measurement("MiMC-x7"));
+    }
+
+    // Estimate the cost a Solidity verifier contract would pay: the byte
+    // length of the calldata it would receive, plus the fixed EC operation
+    // count (independent of circuit size) it would execute.
+    println!("Benchmarking EVM verifier calldata...");
+    let evm_x = <Bn256 as ScalarEngine>::Fr::rand(rng);
+    let evm_k = <Bn256 as ScalarEngine>::Fr::rand(rng);
+    let evm_image = mimc::<Bn256>(evm_x, evm_k, &constants);
+    let c = MiMCDemo::<Bn256> {
+        repetitions: 1,
+        x: Some(evm_x),
+        k: Some(evm_k),
+        constants: &constants,
+    };
+    let evm_proof = create_random_proof(c, &params, rng).unwrap();
+    let calldata = to_evm_calldata::<Bn256>(&evm_proof, &[evm_image]);
+    let vk_calldata = vk_to_evm_calldata::<Bn256>(&params.vk);
+    let (pairings, scalar_muls) = onchain_ec_ops(1);
+    println!(
+        "MiMC: proof+inputs calldata {} bytes, vk calldata {} bytes, {} pairings + {} scalar muls on-chain",
+        calldata.len(),
+        vk_calldata.len(),
+        pairings,
+        scalar_muls
+    );
+
+    if let Some(path) = std::env::args().nth(1) {
+        match run.write_to_path(&path) {
+            Ok(()) => println!("Wrote structured results to {}", path),
+            Err(e) => eprintln!("failed to write results to {}: {}", path, e),
+        }
     }
 }
 
@@ -1,14 +1,19 @@
 use rand::thread_rng;
-use rand::{XorShiftRng, SeedableRng, Rng};
+use rand::{XorShiftRng, SeedableRng, Rng, Rand};
+use std::time::Instant;
 use crypto::sha2::{Sha256};
 use crypto::digest::Digest;
+use bellman_ce::pairing::{CurveAffine, CurveProjective, Engine};
 use bellman_ce::pairing::bn256::{Bn256}; // use Bn256 curve
+use bellman_ce::pairing::bls12_381::{Bls12}; // use BLS12-381 curve
+use bellman_ce::pairing::ff::{Field, PrimeField};
 use bellman_ce::{
     Circuit,
     ConstraintSystem,
     SynthesisError,
     groth16::{
-        create_random_proof, generate_random_parameters, prepare_verifying_key, verify_proof
+        create_random_proof, generate_random_parameters, prepare_verifying_key, verify_proof,
+        Parameters, PreparedVerifyingKey, Proof,
     }
 };
 use sapling_crypto_ce::{
@@ -19,12 +24,25 @@ use sapling_crypto_ce::{
     },
 };
 
+mod evm;
+mod results;
+use evm::{onchain_ec_ops, to_evm_calldata, vk_to_evm_calldata};
+use results::{Measurement, RunRecord};
+
+fn duration_secs(d: std::time::Duration) -> f64 {
+    d.subsec_nanos() as f64 / 1_000_000_000f64 + d.as_secs() as f64
+}
+
+// Batch sizes swept by `bench_batch_verification` to find the crossover
+// where verifying proofs as a batch beats verifying them one at a time.
+const BATCH_SIZES: [usize; 4] = [1, 8, 64, 256];
+
 struct Sha256Demo {
     input_data: Vec<u8>,
 }
 
-impl Circuit<Bn256> for Sha256Demo {
-    fn synthesize<CS: ConstraintSystem<Bn256>>(self, mut cs: &mut CS) -> Result<(), SynthesisError> {
+impl<E: Engine> Circuit<E> for Sha256Demo {
+    fn synthesize<CS: ConstraintSystem<E>>(self, mut cs: &mut CS) -> Result<(), SynthesisError> {
         let mut h = Sha256::new();
 
         h.input(&self.input_data);
@@ -47,21 +65,165 @@ impl Circuit<Bn256> for Sha256Demo {
     }
 }
 
-fn eval_sha256(num_bytes : usize) {
+/// Chains two `sha256` gadget invocations -- hash the preimage, then hash
+/// the 256-bit digest again -- to model the double-SHA construction used
+/// in Bitcoin-style preimage proofs. The second call consumes the
+/// `Boolean`s the first call outputs directly; only the final digest is
+/// multipacked into public inputs.
+struct Sha256dDemo {
+    input_data: Vec<u8>,
+}
+
+impl<E: Engine> Circuit<E> for Sha256dDemo {
+    fn synthesize<CS: ConstraintSystem<E>>(self, mut cs: &mut CS) -> Result<(), SynthesisError> {
+        let mut foobar : Vec<Boolean> = [].to_vec();
+        for (byte_i, input_byte) in self.input_data.into_iter().enumerate() {
+            for bit_i in (0..8).rev() {
+                let cs = cs.namespace(|| format!("input bit {} {}", byte_i, bit_i));
+                foobar.push(AllocatedBit::alloc(cs, Some((input_byte >> bit_i) & 1u8 == 1u8)).unwrap().into());
+            }
+        }
+
+        let r1 = &mut cs;
+        let first_hash = sha256(r1, &foobar).unwrap();
+        let r1 = &mut cs;
+        let second_hash = sha256(r1, &first_hash).unwrap();
+        multipack::pack_into_inputs(cs, &second_hash)?;
+        Ok(())
+    }
+}
+
+// bellman_ce does not expose `create_random_proof_batch` / `verify_proofs_batch`
+// (unlike bellperson), so batching is implemented here directly: each of the
+// N individual pairing checks e(A_i, B_i) =?= e(alpha,beta).e(sum_inputs_i,gamma).e(C_i,delta)
+// is randomized by a fresh scalar r_i and folded into one miller loop plus a
+// single final exponentiation, collapsing 3N pairings down to O(N) miller
+// loop terms and one final exponentiation. See bellman_mimc for the same
+// construction with a fuller derivation.
+fn verify_proofs_batch<E: Engine, R: Rng>(
+    pvk: &PreparedVerifyingKey<E>,
+    rng: &mut R,
+    proofs: &[Proof<E>],
+    public_inputs: &[Vec<E::Fr>],
+) -> Result<bool, SynthesisError> {
+    assert_eq!(proofs.len(), public_inputs.len());
+
+    let mut prepared_a = Vec::with_capacity(proofs.len());
+    let mut prepared_b = Vec::with_capacity(proofs.len());
+    let mut acc_for_gamma = <E::G1Affine as CurveAffine>::Projective::zero();
+    let mut acc_for_delta = <E::G1Affine as CurveAffine>::Projective::zero();
+    let mut sum_r = E::Fr::zero();
+
+    for (proof, inputs) in proofs.iter().zip(public_inputs.iter()) {
+        if (inputs.len() + 1) != pvk.ic.len() {
+            return Err(SynthesisError::MalformedVerifyingKey);
+        }
+
+        let r = E::Fr::rand(rng);
+        sum_r.add_assign(&r);
+
+        let mut scaled_a = proof.a.into_projective();
+        scaled_a.mul_assign(r.into_repr());
+        prepared_a.push(scaled_a.into_affine());
+        prepared_b.push(proof.b);
+
+        let mut acc = pvk.ic[0].into_projective();
+        for (input, base) in inputs.iter().zip(pvk.ic.iter().skip(1)) {
+            acc.add_assign(&base.mul(input.into_repr()));
+        }
+        acc.mul_assign(r.into_repr());
+        acc_for_gamma.add_assign(&acc);
+
+        let mut scaled_c = proof.c.into_projective();
+        scaled_c.mul_assign(r.into_repr());
+        acc_for_delta.add_assign(&scaled_c);
+    }
+
+    let acc_for_gamma = acc_for_gamma.into_affine().prepare();
+    let acc_for_delta = acc_for_delta.into_affine().prepare();
+    let prepared_a: Vec<_> = prepared_a.iter().map(|a| a.prepare()).collect();
+    let prepared_b: Vec<_> = prepared_b.iter().map(|b| b.prepare()).collect();
+
+    let mut pairs: Vec<_> = prepared_a.iter().zip(prepared_b.iter()).collect();
+    pairs.push((&acc_for_gamma, &pvk.neg_gamma_g2));
+    pairs.push((&acc_for_delta, &pvk.neg_delta_g2));
+
+    let lhs = E::final_exponentiation(&E::miller_loop(pairs.iter().map(|(a, b)| (*a, *b)))).unwrap();
+    let rhs = pvk.alpha_g1_beta_g2.pow(sum_r.into_repr());
+
+    Ok(lhs == rhs)
+}
+
+fn bench_batch_verification<E: Engine>(params: &Parameters<E>, input_data: &[u8]) {
+    let rng = &mut thread_rng();
+    let pvk = prepare_verifying_key(&params.vk);
+
+    let mut hasher = Sha256::new();
+    hasher.input(input_data);
+    let mut hash_bytes = [0u8; 32];
+    hasher.result(&mut hash_bytes);
+    let hash_bits = multipack::bytes_to_bits(&hash_bytes);
+    let inputs = multipack::compute_multipacking::<E>(&hash_bits);
+
+    for &batch_size in BATCH_SIZES.iter() {
+        let proofs: Vec<Proof<E>> = (0..batch_size)
+            .map(|_| {
+                let c = Sha256Demo {
+                    input_data: input_data.to_vec(),
+                };
+                create_random_proof(c, params, rng).unwrap()
+            })
+            .collect();
+
+        let start = Instant::now();
+        for _ in 0..batch_size {
+            let c = Sha256Demo {
+                input_data: input_data.to_vec(),
+            };
+            create_random_proof(c, params, rng).unwrap();
+        }
+        let batch_proving = start.elapsed();
+        let per_proof_proving =
+            (batch_proving.as_secs() as f64 + batch_proving.subsec_nanos() as f64 / 1e9)
+                / (batch_size as f64);
+
+        let all_inputs: Vec<Vec<E::Fr>> =
+            (0..batch_size).map(|_| inputs.clone()).collect();
+
+        let start = Instant::now();
+        for proof in proofs.iter() {
+            assert!(verify_proof(&pvk, proof, &inputs).unwrap());
+        }
+        let individual_verifying = start.elapsed();
+
+        let start = Instant::now();
+        assert!(verify_proofs_batch(&pvk, rng, &proofs, &all_inputs).unwrap());
+        let batch_verifying = start.elapsed();
+
+        println!(
+            "batch size {:4}: per-proof proving {:?}, individual verify {:?}, batch verify {:?}",
+            batch_size, per_proof_proving, individual_verifying, batch_verifying
+        );
+    }
+}
+
+fn eval_sha256<E: Engine>(num_bytes : usize) -> Measurement {
     let mut rng = XorShiftRng::from_seed([0x5dbe6259, 0x8d313d76, 0x3237db17, 0xe5bc0654]);
     let rng_foo = &mut thread_rng();
 
     let input_len = num_bytes;
     let data: Vec<u8> = (0..input_len).map(|_| rng.gen()).collect();
 
-    println!("creating proving key");    
+    println!("creating proving key");
+    let param_gen_start = Instant::now();
     let params = {
         let c = Sha256Demo {
             input_data: data,
         };
 
-        generate_random_parameters(c, rng_foo).unwrap()
+        generate_random_parameters::<E, _, _>(c, rng_foo).unwrap()
     };
+    let param_gen = param_gen_start.elapsed();
 
     println!("creating verification key");
     let pvk = prepare_verifying_key(&params.vk);
@@ -74,31 +236,268 @@ fn eval_sha256(num_bytes : usize) {
 
     let r1 = &mut hash_bytes;
     hasher.result(r1);
-    
+
     let more_c = Sha256Demo {
         input_data: more_data,
     };
     println!("constraints {:?} ", params.a.len());
     let start = std::time::SystemTime::now();
     let proof = create_random_proof(more_c, &params, rng_foo).unwrap();
-    println!("Prover time: {:?}",std::time::SystemTime::now().duration_since(start).unwrap());
+    let proving = std::time::SystemTime::now().duration_since(start).unwrap();
+    println!("Prover time: {:?}", proving);
+
+    let mut proof_vec = vec![];
+    proof.write(&mut proof_vec).unwrap();
 
     let hash_bits = multipack::bytes_to_bits(r1);
-    let inputs = multipack::compute_multipacking::<Bn256>(&hash_bits);
+    let inputs = multipack::compute_multipacking::<E>(&hash_bits);
+
+    let start = Instant::now();
+    let result = verify_proof(
+        &pvk,
+        &proof,
+        &inputs
+    ).unwrap();
+    let verifying = start.elapsed();
+    assert!(result, "Proof is correct");
+
+    Measurement {
+        circuit: "SHA256".to_string(),
+        input_size: num_bytes,
+        constraints: params.a.len(),
+        param_gen_secs: duration_secs(param_gen),
+        proving_secs: duration_secs(proving),
+        verifying_secs: duration_secs(verifying),
+        proof_size_bytes: proof_vec.len(),
+    }
+}
+
+fn eval_sha256d<E: Engine>(num_bytes : usize) -> Measurement {
+    let mut rng = XorShiftRng::from_seed([0x5dbe6259, 0x8d313d76, 0x3237db17, 0xe5bc0654]);
+    let rng_foo = &mut thread_rng();
+
+    let input_len = num_bytes;
+    let data: Vec<u8> = (0..input_len).map(|_| rng.gen()).collect();
+
+    println!("creating proving key (sha256d)");
+    let param_gen_start = Instant::now();
+    let params = {
+        let c = Sha256dDemo {
+            input_data: data,
+        };
+
+        generate_random_parameters::<E, _, _>(c, rng_foo).unwrap()
+    };
+    let param_gen = param_gen_start.elapsed();
+
+    println!("creating verification key (sha256d)");
+    let pvk = prepare_verifying_key(&params.vk);
+
+    let more_data: Vec<u8> = (0..input_len).map(|_| rng.gen()).collect();
 
+    let mut first_hasher = Sha256::new();
+    first_hasher.input(&more_data);
+    let mut first_hash = [0u8; 32];
+    first_hasher.result(&mut first_hash);
+
+    let mut second_hasher = Sha256::new();
+    second_hasher.input(&first_hash);
+    let mut second_hash = [0u8; 32];
+    second_hasher.result(&mut second_hash);
+
+    let more_c = Sha256dDemo {
+        input_data: more_data,
+    };
+    println!("constraints (sha256d) {:?} ", params.a.len());
+    let start = Instant::now();
+    let proof = create_random_proof(more_c, &params, rng_foo).unwrap();
+    let proving = start.elapsed();
+    println!("Prover time (sha256d): {:?}", proving);
+
+    let mut proof_vec = vec![];
+    proof.write(&mut proof_vec).unwrap();
+
+    let hash_bits = multipack::bytes_to_bits(&second_hash);
+    let inputs = multipack::compute_multipacking::<E>(&hash_bits);
+
+    let start = Instant::now();
     let result = verify_proof(
         &pvk,
         &proof,
         &inputs
     ).unwrap();
+    let verifying = start.elapsed();
     assert!(result, "Proof is correct");
+
+    Measurement {
+        circuit: "SHA256d".to_string(),
+        input_size: num_bytes,
+        constraints: params.a.len(),
+        param_gen_secs: duration_secs(param_gen),
+        proving_secs: duration_secs(proving),
+        verifying_secs: duration_secs(verifying),
+        proof_size_bytes: proof_vec.len(),
+    }
+}
+
+struct CurveBenchResult {
+    curve_name: &'static str,
+    constraints: usize,
+    param_gen: std::time::Duration,
+    proving: std::time::Duration,
+    verifying: std::time::Duration,
+    proof_size_bytes: usize,
+}
+
+// Runs Sha256Demo on `num_bytes` of input on the given curve, so the same
+// circuit can be compared across curves side by side.
+fn bench_sha256_curve<E: Engine>(curve_name: &'static str, num_bytes: usize) -> CurveBenchResult {
+    let mut rng = XorShiftRng::from_seed([0x5dbe6259, 0x8d313d76, 0x3237db17, 0xe5bc0654]);
+    let rng_foo = &mut thread_rng();
+
+    let data: Vec<u8> = (0..num_bytes).map(|_| rng.gen()).collect();
+
+    let start = Instant::now();
+    let params = {
+        let c = Sha256Demo {
+            input_data: data.clone(),
+        };
+        generate_random_parameters::<E, _, _>(c, rng_foo).unwrap()
+    };
+    let param_gen = start.elapsed();
+
+    let pvk = prepare_verifying_key(&params.vk);
+
+    let mut hasher = Sha256::new();
+    hasher.input(&data);
+    let mut hash_bytes = [0u8; 32];
+    hasher.result(&mut hash_bytes);
+    let hash_bits = multipack::bytes_to_bits(&hash_bytes);
+    let inputs = multipack::compute_multipacking::<E>(&hash_bits);
+
+    let c = Sha256Demo { input_data: data };
+
+    let start = Instant::now();
+    let proof = create_random_proof(c, &params, rng_foo).unwrap();
+    let proving = start.elapsed();
+
+    let mut proof_vec = vec![];
+    proof.write(&mut proof_vec).unwrap();
+
+    let start = Instant::now();
+    assert!(verify_proof(&pvk, &proof, &inputs).unwrap());
+    let verifying = start.elapsed();
+
+    CurveBenchResult {
+        curve_name,
+        constraints: params.a.len(),
+        param_gen,
+        proving,
+        verifying,
+        proof_size_bytes: proof_vec.len(),
+    }
+}
+
+fn print_curve_comparison(results: &[CurveBenchResult]) {
+    println!(
+        "{:>10} | {:>12} | {:>15} | {:>15} | {:>15}",
+        "curve", "constraints", "param-gen", "proving", "verifying"
+    );
+    for r in results {
+        println!(
+            "{:>10} | {:>12} | {:>15?} | {:>15?} | {:>15?}",
+            r.curve_name, r.constraints, r.param_gen, r.proving, r.verifying
+        );
+    }
+}
+
+impl CurveBenchResult {
+    fn to_measurement(&self, circuit: &str) -> Measurement {
+        Measurement {
+            circuit: circuit.to_string(),
+            input_size: 55,
+            constraints: self.constraints,
+            param_gen_secs: duration_secs(self.param_gen),
+            proving_secs: duration_secs(self.proving),
+            verifying_secs: duration_secs(self.verifying),
+            proof_size_bytes: self.proof_size_bytes,
+        }
+    }
 }
 
 fn main() {
+    let mut run = RunRecord::new("BN256");
+
     const NUM_HASHES : usize = 10;
     for i in 0..NUM_HASHES {
         let num_bytes = ((i + 1) * 64) - 9;
         println!("Hashing {:?} bytes", num_bytes);
-        eval_sha256(num_bytes);
+        run.push(eval_sha256::<Bn256>(num_bytes));
+    }
+
+    // Same byte-length sweep, but for SHA-256d (double SHA-256), to show
+    // the marginal cost of the second compression round.
+    for i in 0..NUM_HASHES {
+        let num_bytes = ((i + 1) * 64) - 9;
+        println!("Hashing {:?} bytes (sha256d)", num_bytes);
+        run.push(eval_sha256d::<Bn256>(num_bytes));
+    }
+
+    // Batch proof generation/verification, swept across BATCH_SIZES to find
+    // where batch verification wins over verifying each proof one at a time.
+    println!("Benchmarking batch verification...");
+    let mut rng = XorShiftRng::from_seed([0x5dbe6259, 0x8d313d76, 0x3237db17, 0xe5bc0654]);
+    let rng_foo = &mut thread_rng();
+    let input_data: Vec<u8> = (0..55).map(|_| rng.gen()).collect();
+    let params = {
+        let c = Sha256Demo {
+            input_data: input_data.clone(),
+        };
+        generate_random_parameters::<Bn256, _, _>(c, rng_foo).unwrap()
+    };
+    bench_batch_verification(&params, &input_data);
+
+    // Same circuit, different curve: how much does the curve choice cost?
+    println!("Benchmarking SHA256 across curves...");
+    let curve_results = vec![
+        bench_sha256_curve::<Bn256>("BN256", 55),
+        bench_sha256_curve::<Bls12>("BLS12-381", 55),
+    ];
+    print_curve_comparison(&curve_results);
+    for r in &curve_results {
+        run.push(r.to_measurement("SHA256"));
+    }
+
+    // Estimate the cost a Solidity verifier contract would pay: the byte
+    // length of the calldata it would receive, plus the fixed EC operation
+    // count (independent of circuit size) it would execute.
+    println!("Benchmarking EVM verifier calldata...");
+    let mut hasher = Sha256::new();
+    hasher.input(&input_data);
+    let mut hash_bytes = [0u8; 32];
+    hasher.result(&mut hash_bytes);
+    let hash_bits = multipack::bytes_to_bits(&hash_bytes);
+    let inputs = multipack::compute_multipacking::<Bn256>(&hash_bits);
+
+    let c = Sha256Demo {
+        input_data: input_data.clone(),
+    };
+    let evm_proof = create_random_proof(c, &params, rng_foo).unwrap();
+    let calldata = to_evm_calldata::<Bn256>(&evm_proof, &inputs);
+    let vk_calldata = vk_to_evm_calldata::<Bn256>(&params.vk);
+    let (pairings, scalar_muls) = onchain_ec_ops(inputs.len());
+    println!(
+        "SHA256: proof+inputs calldata {} bytes, vk calldata {} bytes, {} pairings + {} scalar muls on-chain",
+        calldata.len(),
+        vk_calldata.len(),
+        pairings,
+        scalar_muls
+    );
+
+    if let Some(path) = std::env::args().nth(1) {
+        match run.write_to_path(&path) {
+            Ok(()) => println!("Wrote structured results to {}", path),
+            Err(e) => eprintln!("failed to write results to {}: {}", path, e),
+        }
     }
 }
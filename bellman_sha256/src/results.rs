@@ -0,0 +1,83 @@
+//! Structured benchmark results, so runs can be diffed or plotted in CI
+//! instead of scraped from stdout.
+
+use std::fs::File;
+use std::io::{self, Write};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::Serialize;
+
+#[derive(Serialize)]
+pub struct Measurement {
+    pub circuit: String,
+    pub input_size: usize,
+    pub constraints: usize,
+    pub param_gen_secs: f64,
+    pub proving_secs: f64,
+    pub verifying_secs: f64,
+    pub proof_size_bytes: usize,
+}
+
+#[derive(Serialize)]
+pub struct RunRecord {
+    pub curve: String,
+    pub timestamp_secs: u64,
+    pub measurements: Vec<Measurement>,
+}
+
+impl RunRecord {
+    pub fn new(curve: &str) -> Self {
+        let timestamp_secs = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+        RunRecord {
+            curve: curve.to_string(),
+            timestamp_secs,
+            measurements: Vec::new(),
+        }
+    }
+
+    pub fn push(&mut self, measurement: Measurement) {
+        self.measurements.push(measurement);
+    }
+
+    /// Writes the run as CSV if `path` ends in `.csv`, otherwise as JSON.
+    pub fn write_to_path(&self, path: &str) -> io::Result<()> {
+        if path.ends_with(".csv") {
+            self.write_csv(path)
+        } else {
+            self.write_json(path)
+        }
+    }
+
+    fn write_json(&self, path: &str) -> io::Result<()> {
+        let file = File::create(path)?;
+        serde_json::to_writer_pretty(file, self)
+            .map_err(|e| io::Error::new(io::ErrorKind::Other, e))
+    }
+
+    fn write_csv(&self, path: &str) -> io::Result<()> {
+        let mut file = File::create(path)?;
+        writeln!(
+            file,
+            "curve,timestamp_secs,circuit,input_size,constraints,param_gen_secs,proving_secs,verifying_secs,proof_size_bytes"
+        )?;
+        for m in &self.measurements {
+            writeln!(
+                file,
+                "{},{},{},{},{},{},{},{},{}",
+                self.curve,
+                self.timestamp_secs,
+                m.circuit,
+                m.input_size,
+                m.constraints,
+                m.param_gen_secs,
+                m.proving_secs,
+                m.verifying_secs,
+                m.proof_size_bytes
+            )?;
+        }
+        Ok(())
+    }
+}